@@ -0,0 +1,98 @@
+//! Benchmarking setup for the commodity pallet.
+//!
+//! `mint`, `burn`, and `transfer` are O(1) against `OwnedCommodities` (a `double_map`), so they
+//! are benchmarked with a fixed setup. `destroy_accumulated` removes up to `n` commodities in a
+//! single call, so it is parameterized by `n`.
+
+use super::*;
+
+use codec::{Decode, Encode};
+use frame_benchmarking::{account, benchmarks_instance, whitelisted_caller};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn info<T: Trait<I>, I: Instance>(seed: u32) -> T::CommodityInfo {
+    let hash = T::Hashing::hash_of(&seed);
+    T::CommodityInfo::decode(&mut &hash.encode()[..]).unwrap_or_default()
+}
+
+fn mint_to<T: Trait<I>, I: Instance>(who: &T::AccountId, n: u32) -> dispatch::DispatchResult {
+    for i in 0..n {
+        <Module<T, I> as UniqueAssets<_>>::mint(who, info::<T, I>(i))?;
+    }
+    Ok(())
+}
+
+benchmarks_instance! {
+    mint {
+        let owner_account: T::AccountId = account("owner", 0, SEED);
+    }: _(RawOrigin::Root, owner_account.clone(), info::<T, I>(0))
+    verify {
+        assert_eq!(Module::<T, I>::total_for_account(&owner_account), 1);
+    }
+
+    burn {
+        let owner_account: T::AccountId = account("owner", 0, SEED);
+        mint_to::<T, I>(&owner_account, 1)?;
+        let commodity_id = T::Hashing::hash_of(&info::<T, I>(0));
+    }: _(RawOrigin::Signed(owner_account.clone()), commodity_id)
+    verify {
+        assert_eq!(Module::<T, I>::total_for_account(&owner_account), 0);
+    }
+
+    transfer {
+        let owner_account: T::AccountId = account("owner", 0, SEED);
+        mint_to::<T, I>(&owner_account, 1)?;
+        let dest_account: T::AccountId = whitelisted_caller();
+        let commodity_id = T::Hashing::hash_of(&info::<T, I>(0));
+    }: _(RawOrigin::Signed(owner_account), dest_account.clone(), commodity_id)
+    verify {
+        assert_eq!(Module::<T, I>::total_for_account(&dest_account), 1);
+    }
+
+    destroy_accumulated {
+        let n in 1 .. T::RemoveKeyLimit::get();
+        let owner_account: T::AccountId = account("owner", 0, SEED);
+        mint_to::<T, I>(&owner_account, n)?;
+        Module::<T, I>::start_destroy(RawOrigin::Root.into(), owner_account.clone())?;
+    }: _(RawOrigin::Root, owner_account.clone())
+    verify {
+        assert_eq!(Module::<T, I>::total_for_account(&owner_account), 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{new_test_ext, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn mint() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_mint::<Test>());
+        });
+    }
+
+    #[test]
+    fn burn() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_burn::<Test>());
+        });
+    }
+
+    #[test]
+    fn transfer() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_transfer::<Test>());
+        });
+    }
+
+    #[test]
+    fn destroy_accumulated() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_destroy_accumulated::<Test>());
+        });
+    }
+}
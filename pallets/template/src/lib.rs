@@ -16,6 +16,12 @@ use sp_std::{cmp::Eq, fmt::Debug, vec::Vec};
 pub mod nft;
 pub use crate::nft::UniqueAssets;
 
+mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 #[cfg(test)]
 mod mock;
 
@@ -31,7 +37,11 @@ pub trait Trait<I = DefaultInstance>: frame_system::Trait {
     type CommodityLimit: Get<u128>;
     /// The maximum number of this type of commodity that any single account may own.
     type UserCommodityLimit: Get<u64>;
+    /// The maximum number of commodities that `destroy_accumulated` will remove in a single call.
+    type RemoveKeyLimit: Get<u32>;
     type Event: From<Event<Self, I>> + Into<<Self as frame_system::Trait>::Event>;
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
 }
 /// The runtime system's hashing algorithm is used to uniquely identify commodities.
 pub type CommodityId<T> = <T as frame_system::Trait>::Hash;
@@ -51,10 +61,25 @@ decl_storage! {
         Burned get(fn burned): u128 = 0;
         /// The total number of this type of commodity owned by an account.
         TotalForAccount get(fn total_for_account): map hasher(blake2_128_concat) T::AccountId => u64 = 0;
-        /// A mapping from an account to a list of all of the commodities of this type that are owned by it.
-        CommoditiesForAccount get(fn commodities_for_account): map hasher(blake2_128_concat) T::AccountId => Vec<Commodity<T, I>>;
+        /// A mapping from an account and a commodity ID it owns to the commodity's info, allowing
+        /// O(1) insertion, removal, and lookup regardless of the size of the account's holdings.
+        /// Iterate with `iter_prefix` on the account to page through everything it owns.
+        OwnedCommodities get(fn owned_commodities):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(identity) CommodityId<T> => T::CommodityInfo;
         /// A mapping from a commodity ID to the account that owns it.
         AccountForCommodity get(fn account_for_commodity): map hasher(identity) CommodityId<T> => T::AccountId;
+        /// The account, if any, approved to transfer a specific commodity on the owner's behalf
+        /// via `transfer_from`.
+        ApprovalForCommodity get(fn approved_for): map hasher(identity) CommodityId<T> => Option<T::AccountId>;
+        /// Whether an operator is approved to transfer all of an owner's commodities of this type
+        /// via `transfer_from`.
+        OperatorApprovals get(fn is_approved_for_all):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) T::AccountId => bool;
+        /// The number of commodities destroyed so far for an account whose destruction is in
+        /// progress via `start_destroy` / `destroy_accumulated`.
+        DestroyCursor get(fn destroy_cursor): map hasher(blake2_128_concat) T::AccountId => Option<u32>;
+        /// Whether a commodity is frozen and thus may not be transferred or burned.
+        FrozenCommodities get(fn is_frozen): map hasher(identity) CommodityId<T> => bool;
     }
 
     add_extra_genesis {
@@ -91,6 +116,18 @@ decl_event!(
         Minted(CommodityId, AccountId),
         /// Ownership of the commodity has been transferred to the account.
 		Transferred(CommodityId, AccountId),
+		/// An account has been approved to transfer a commodity on the owner's behalf.
+		/// [owner, approved, commodity_id]
+		Approval(AccountId, AccountId, CommodityId),
+		/// An operator has been approved, or had its approval revoked, to manage all commodities
+		/// owned by an account. [owner, operator, approved]
+		ApprovalForAll(AccountId, AccountId, bool),
+		/// All commodities owned by the account have been destroyed. [account, count]
+		Destroyed(AccountId, u32),
+		/// A commodity has been frozen and may no longer be transferred or burned.
+		Frozen(CommodityId),
+		/// A previously frozen commodity has been thawed.
+		Thawed(CommodityId),
 		SomethingStored(u32, AccountId),
     }
 );
@@ -110,6 +147,14 @@ decl_error! {
         // Thrown when an attempt is made to mint or transfer a commodity to an account that already
         // owns the maximum number of this type of commodity.
         TooManyCommoditiesForAccount,
+        // Thrown when the caller of `transfer_from` is neither the owner of the commodity, nor
+        // the account approved to spend it, nor an approved operator for the owner.
+        NotApproved,
+        // Thrown when `destroy_accumulated` is called for an account that has not had
+        // `start_destroy` called for it.
+        DestructionNotStarted,
+        // Thrown when an attempt is made to transfer or burn a frozen commodity.
+        Frozen,
     }
 }
 
@@ -143,7 +188,7 @@ decl_module! {
 		// 	Ok(())
 		// }
 
-		#[weight = 10_000]
+		#[weight = T::WeightInfo::mint()]
         pub fn mint(origin, owner_account: T::AccountId, commodity_info: T::CommodityInfo) -> dispatch::DispatchResult {
             T::CommodityAdmin::ensure_origin(origin)?;
 
@@ -151,8 +196,8 @@ decl_module! {
             Self::deposit_event(RawEvent::Minted(commodity_id, owner_account.clone()));
             Ok(())
 		}
-		
-		#[weight = 10_000]
+
+		#[weight = T::WeightInfo::burn()]
         pub fn burn(origin, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(who == Self::account_for_commodity(&commodity_id), Error::<T, I>::NotCommodityOwner);
@@ -161,7 +206,8 @@ decl_module! {
             Self::deposit_event(RawEvent::Burned(commodity_id.clone()));
             Ok(())
 		}
-		#[weight = 10_000]
+
+		#[weight = T::WeightInfo::transfer()]
         pub fn transfer(origin, dest_account: T::AccountId, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(who == Self::account_for_commodity(&commodity_id), Error::<T, I>::NotCommodityOwner);
@@ -171,7 +217,130 @@ decl_module! {
             Ok(())
         }
 
-		
+        /// Authorize `spender` to transfer the given commodity on the caller's behalf via
+        /// `transfer_from`; burning remains reserved to the owner.
+        /// The approval is cleared automatically the next time the commodity is transferred or
+        /// burned.
+        #[weight = 10_000]
+        pub fn approve(origin, spender: T::AccountId, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            let owner = Self::account_for_commodity(&commodity_id);
+            ensure!(owner != T::AccountId::default(), Error::<T, I>::NonexistentCommodity);
+            ensure!(who == owner, Error::<T, I>::NotCommodityOwner);
+
+            ApprovalForCommodity::<T, I>::insert(&commodity_id, &spender);
+            Self::deposit_event(RawEvent::Approval(owner, spender, commodity_id));
+            Ok(())
+        }
+
+        /// Approve or revoke `operator` as a manager of all commodities of this type owned by the
+        /// caller.
+        #[weight = 10_000]
+        pub fn set_approval_for_all(origin, operator: T::AccountId, approved: bool) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            OperatorApprovals::<T, I>::insert(&who, &operator, approved);
+            Self::deposit_event(RawEvent::ApprovalForAll(who, operator, approved));
+            Ok(())
+        }
+
+        /// Transfer a commodity on behalf of its owner. The caller must be the owner, the
+        /// account approved for this specific commodity, or an approved operator for `from`.
+        #[weight = 10_000]
+        pub fn transfer_from(origin, from: T::AccountId, dest_account: T::AccountId, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            let who = ensure_signed(origin)?;
+            let owner = Self::account_for_commodity(&commodity_id);
+            ensure!(owner != T::AccountId::default(), Error::<T, I>::NonexistentCommodity);
+            ensure!(owner == from, Error::<T, I>::NotCommodityOwner);
+            ensure!(
+                who == owner
+                    || Self::approved_for(&commodity_id) == Some(who.clone())
+                    || Self::is_approved_for_all(&owner, &who),
+                Error::<T, I>::NotApproved
+            );
+
+            <Self as UniqueAssets<_>>::transfer(&dest_account, &commodity_id)?;
+            Self::deposit_event(RawEvent::Transferred(commodity_id.clone(), dest_account.clone()));
+            Ok(())
+        }
+
+        /// Mark `account` for destruction. Subsequent calls to `destroy_accumulated` will remove
+        /// its commodities in batches of at most `RemoveKeyLimit`.
+        #[weight = 10_000]
+        pub fn start_destroy(origin, account: T::AccountId) -> dispatch::DispatchResult {
+            T::CommodityAdmin::ensure_origin(origin)?;
+
+            DestroyCursor::<T, I>::insert(&account, 0u32);
+            Ok(())
+        }
+
+        /// Remove up to `RemoveKeyLimit` of the commodities owned by an account marked via
+        /// `start_destroy`. Repeated calls finish the job for accounts with larger holdings;
+        /// the final call clears all per-account state and emits `Destroyed`. Fails without
+        /// removing anything if any of the selected commodities is frozen; thaw it first.
+        #[weight = T::WeightInfo::destroy_accumulated(T::RemoveKeyLimit::get())]
+        pub fn destroy_accumulated(origin, account: T::AccountId) -> dispatch::DispatchResultWithPostInfo {
+            T::CommodityAdmin::ensure_origin(origin)?;
+
+            let mut destroyed = Self::destroy_cursor(&account).ok_or(Error::<T, I>::DestructionNotStarted)?;
+            let limit = T::RemoveKeyLimit::get() as usize;
+            let ids: Vec<CommodityId<T>> = OwnedCommodities::<T, I>::iter_prefix(&account)
+                .map(|(commodity_id, _)| commodity_id)
+                .take(limit)
+                .collect();
+            let to_remove = ids.len();
+
+            ensure!(
+                ids.iter().all(|commodity_id| !Self::is_frozen(commodity_id)),
+                Error::<T, I>::Frozen
+            );
+
+            for commodity_id in &ids {
+                OwnedCommodities::<T, I>::remove(&account, commodity_id);
+                AccountForCommodity::<T, I>::remove(commodity_id);
+                ApprovalForCommodity::<T, I>::remove(commodity_id);
+                FrozenCommodities::<T, I>::remove(commodity_id);
+            }
+
+            Total::<I>::mutate(|total| *total -= to_remove as u128);
+            Burned::<I>::mutate(|total| *total += to_remove as u128);
+            TotalForAccount::<T, I>::mutate(&account, |total| *total -= to_remove as u64);
+            destroyed += to_remove as u32;
+
+            if to_remove < limit {
+                TotalForAccount::<T, I>::remove(&account);
+                DestroyCursor::<T, I>::remove(&account);
+                Self::deposit_event(RawEvent::Destroyed(account, destroyed));
+            } else {
+                DestroyCursor::<T, I>::insert(&account, destroyed);
+            }
+
+            Ok(Some(T::WeightInfo::destroy_accumulated(to_remove as u32)).into())
+        }
+
+        /// Freeze a commodity so it cannot be transferred or burned until thawed.
+        #[weight = 10_000]
+        pub fn freeze(origin, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            T::CommodityAdmin::ensure_origin(origin)?;
+            ensure!(
+                AccountForCommodity::<T, I>::contains_key(&commodity_id),
+                Error::<T, I>::NonexistentCommodity
+            );
+
+            FrozenCommodities::<T, I>::insert(&commodity_id, true);
+            Self::deposit_event(RawEvent::Frozen(commodity_id));
+            Ok(())
+        }
+
+        /// Thaw a previously frozen commodity, allowing it to be transferred or burned again.
+        #[weight = 10_000]
+        pub fn thaw(origin, commodity_id: CommodityId<T>) -> dispatch::DispatchResult {
+            T::CommodityAdmin::ensure_origin(origin)?;
+
+            FrozenCommodities::<T, I>::remove(&commodity_id);
+            Self::deposit_event(RawEvent::Thawed(commodity_id));
+            Ok(())
+        }
 	}
 }
 
@@ -196,7 +365,7 @@ impl<T: Trait<I>, I: Instance> UniqueAssets<T::AccountId> for Module<T, I> {
     }
 
     fn assets_for_account(account: &T::AccountId) -> Vec<Commodity<T, I>> {
-        Self::commodities_for_account(account)
+        OwnedCommodities::<T, I>::iter_prefix(account).collect()
     }
 
     fn owner_of(commodity_id: &CommodityId<T>) -> T::AccountId {
@@ -224,16 +393,9 @@ impl<T: Trait<I>, I: Instance> UniqueAssets<T::AccountId> for Module<T, I> {
             Error::<T, I>::TooManyCommodities
         );
 
-        let new_commodity = (commodity_id, commodity_info);
-
         Total::<I>::mutate(|total| *total += 1);
         TotalForAccount::<T, I>::mutate(owner_account, |total| *total += 1);
-        CommoditiesForAccount::<T, I>::mutate(owner_account, |commodities| {
-            match commodities.binary_search(&new_commodity) {
-                Ok(_pos) => {} // should never happen
-                Err(pos) => commodities.insert(pos, new_commodity),
-            }
-        });
+        OwnedCommodities::<T, I>::insert(owner_account, commodity_id, commodity_info);
         AccountForCommodity::<T, I>::insert(commodity_id, &owner_account);
 
         Ok(commodity_id)
@@ -245,19 +407,14 @@ impl<T: Trait<I>, I: Instance> UniqueAssets<T::AccountId> for Module<T, I> {
             owner != T::AccountId::default(),
             Error::<T, I>::NonexistentCommodity
         );
-
-        let burn_commodity = (*commodity_id, <T as Trait<I>>::CommodityInfo::default());
+        ensure!(!Self::is_frozen(commodity_id), Error::<T, I>::Frozen);
 
         Total::<I>::mutate(|total| *total -= 1);
         Burned::<I>::mutate(|total| *total += 1);
         TotalForAccount::<T, I>::mutate(&owner, |total| *total -= 1);
-        CommoditiesForAccount::<T, I>::mutate(owner, |commodities| {
-            let pos = commodities
-                .binary_search(&burn_commodity)
-                .expect("We already checked that we have the correct owner; qed");
-            commodities.remove(pos);
-        });
+        OwnedCommodities::<T, I>::remove(&owner, commodity_id);
         AccountForCommodity::<T, I>::remove(&commodity_id);
+        ApprovalForCommodity::<T, I>::remove(&commodity_id);
 
         Ok(())
     }
@@ -271,29 +428,19 @@ impl<T: Trait<I>, I: Instance> UniqueAssets<T::AccountId> for Module<T, I> {
             owner != T::AccountId::default(),
             Error::<T, I>::NonexistentCommodity
         );
+        ensure!(!Self::is_frozen(commodity_id), Error::<T, I>::Frozen);
 
         ensure!(
             Self::total_for_account(dest_account) < T::UserCommodityLimit::get(),
             Error::<T, I>::TooManyCommoditiesForAccount
         );
 
-        let xfer_commodity = (*commodity_id, <T as Trait<I>>::CommodityInfo::default());
-
         TotalForAccount::<T, I>::mutate(&owner, |total| *total -= 1);
         TotalForAccount::<T, I>::mutate(dest_account, |total| *total += 1);
-        let commodity = CommoditiesForAccount::<T, I>::mutate(owner, |commodities| {
-            let pos = commodities
-                .binary_search(&xfer_commodity)
-                .expect("We already checked that we have the correct owner; qed");
-            commodities.remove(pos)
-        });
-        CommoditiesForAccount::<T, I>::mutate(dest_account, |commodities| {
-            match commodities.binary_search(&commodity) {
-                Ok(_pos) => {} // should never happen
-                Err(pos) => commodities.insert(pos, commodity),
-            }
-        });
+        let commodity_info = OwnedCommodities::<T, I>::take(&owner, commodity_id);
+        OwnedCommodities::<T, I>::insert(dest_account, commodity_id, commodity_info);
         AccountForCommodity::<T, I>::insert(&commodity_id, &dest_account);
+        ApprovalForCommodity::<T, I>::remove(&commodity_id);
 
         Ok(())
     }
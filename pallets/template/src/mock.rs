@@ -0,0 +1,78 @@
+//! Mock runtime used to exercise the commodity pallet in `tests.rs`.
+
+use crate::{self as pallet_commodity, Trait};
+use frame_support::{impl_outer_origin, parameter_types};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    Perbill,
+};
+
+impl_outer_origin! {
+    pub enum Origin for Test {}
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Test;
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: u32 = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::from_percent(75);
+}
+
+impl frame_system::Trait for Test {
+    type BaseCallFilter = ();
+    type Origin = Origin;
+    type Call = ();
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = ();
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type MaximumExtrinsicWeight = MaximumBlockWeight;
+    type MaximumBlockLength = MaximumBlockLength;
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type Version = ();
+    type PalletInfo = ();
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+}
+
+parameter_types! {
+    pub const CommodityLimit: u128 = 1_000;
+    pub const UserCommodityLimit: u64 = 5;
+    pub const RemoveKeyLimit: u32 = 3;
+}
+
+impl Trait for Test {
+    type CommodityAdmin = EnsureRoot<u64>;
+    type CommodityInfo = Vec<u8>;
+    type CommodityLimit = CommodityLimit;
+    type UserCommodityLimit = UserCommodityLimit;
+    type RemoveKeyLimit = RemoveKeyLimit;
+    type Event = ();
+    type WeightInfo = ();
+}
+
+pub type Commodity = pallet_commodity::Module<Test>;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap()
+        .into()
+}
@@ -0,0 +1,37 @@
+use frame_support::dispatch;
+use sp_std::vec::Vec;
+
+/// An interface over a non-fungible set of assets. The AssetId type uniquely identifies an
+/// asset in the set, while the AssetInfo type contains metadata common to all assets of this
+/// kind.
+pub trait UniqueAssets<AccountId> {
+    /// The type used to identify unique assets.
+    type AssetId;
+    /// The type used to describe this type of asset.
+    type AssetInfo;
+    /// The maximum number of this type of asset that may exist (minted - burned).
+    type AssetLimit;
+    /// The maximum number of this type of asset that any single account may own.
+    type UserAssetLimit;
+
+    /// The total number of this type of asset that exists (minted - burned).
+    fn total() -> u128;
+    /// The total number of this type of asset that has been burned (may overflow).
+    fn burned() -> u128;
+    /// The total number of this type of asset owned by an account.
+    fn total_for_account(account: &AccountId) -> u64;
+    /// The set of assets owned by an account.
+    fn assets_for_account(account: &AccountId) -> Vec<(Self::AssetId, Self::AssetInfo)>;
+    /// The ID of the account that owns an asset.
+    fn owner_of(asset_id: &Self::AssetId) -> AccountId;
+
+    /// Mint a new asset, assigning ownership to `owner_account`.
+    fn mint(
+        owner_account: &AccountId,
+        asset_info: Self::AssetInfo,
+    ) -> dispatch::result::Result<Self::AssetId, dispatch::DispatchError>;
+    /// Destroy an asset.
+    fn burn(asset_id: &Self::AssetId) -> dispatch::DispatchResult;
+    /// Transfer an asset to a new owner.
+    fn transfer(dest_account: &AccountId, asset_id: &Self::AssetId) -> dispatch::DispatchResult;
+}
@@ -0,0 +1,194 @@
+use crate::{mock::*, Error, UniqueAssets};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::Hash;
+use sp_std::collections::btree_set::BTreeSet;
+
+fn info(n: u8) -> Vec<u8> {
+    vec![n]
+}
+
+#[test]
+fn mint_burn_transfer_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Commodity::mint(Origin::root(), 1, info(0)));
+        assert_eq!(Commodity::total_for_account(&1), 1);
+
+        let commodity_id = Commodity::assets_for_account(&1)[0].0;
+        assert_ok!(Commodity::transfer(Origin::signed(1), 2, commodity_id));
+        assert_eq!(Commodity::total_for_account(&1), 0);
+        assert_eq!(Commodity::total_for_account(&2), 1);
+
+        assert_ok!(Commodity::burn(Origin::signed(2), commodity_id));
+        assert_eq!(Commodity::total_for_account(&2), 0);
+        assert_eq!(Commodity::total(), 0);
+    });
+}
+
+#[test]
+fn approve_then_transfer_from_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Commodity::mint(Origin::root(), 1, info(0)));
+        let commodity_id = Commodity::assets_for_account(&1)[0].0;
+
+        assert_ok!(Commodity::approve(Origin::signed(1), 2, commodity_id));
+        assert_ok!(Commodity::transfer_from(Origin::signed(2), 1, 3, commodity_id));
+        assert_eq!(Commodity::owner_of(&commodity_id), 3);
+
+        // the approval is cleared once spent, so the approved account can't reuse it.
+        assert_noop!(
+            Commodity::transfer_from(Origin::signed(2), 3, 1, commodity_id),
+            Error::<Test>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn transfer_from_without_approval_fails() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Commodity::mint(Origin::root(), 1, info(0)));
+        let commodity_id = Commodity::assets_for_account(&1)[0].0;
+
+        assert_noop!(
+            Commodity::transfer_from(Origin::signed(2), 1, 3, commodity_id),
+            Error::<Test>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn operator_approval_allows_transfer_from() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Commodity::mint(Origin::root(), 1, info(0)));
+        let commodity_id = Commodity::assets_for_account(&1)[0].0;
+
+        assert_ok!(Commodity::set_approval_for_all(Origin::signed(1), 2, true));
+        assert_ok!(Commodity::transfer_from(Origin::signed(2), 1, 3, commodity_id));
+        assert_eq!(Commodity::owner_of(&commodity_id), 3);
+    });
+}
+
+#[test]
+fn revoking_operator_approval_blocks_transfer_from() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Commodity::mint(Origin::root(), 1, info(0)));
+        let commodity_id = Commodity::assets_for_account(&1)[0].0;
+
+        assert_ok!(Commodity::set_approval_for_all(Origin::signed(1), 2, true));
+        assert_ok!(Commodity::set_approval_for_all(Origin::signed(1), 2, false));
+        assert_noop!(
+            Commodity::transfer_from(Origin::signed(2), 1, 3, commodity_id),
+            Error::<Test>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn transfer_clears_a_stale_approval() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Commodity::mint(Origin::root(), 1, info(0)));
+        let commodity_id = Commodity::assets_for_account(&1)[0].0;
+
+        assert_ok!(Commodity::approve(Origin::signed(1), 2, commodity_id));
+        // owner transfers directly, bypassing transfer_from.
+        assert_ok!(Commodity::transfer(Origin::signed(1), 3, commodity_id));
+
+        assert_noop!(
+            Commodity::transfer_from(Origin::signed(2), 3, 1, commodity_id),
+            Error::<Test>::NotApproved
+        );
+    });
+}
+
+#[test]
+fn destroy_accumulated_requires_start_destroy_first() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Commodity::destroy_accumulated(Origin::root(), 1),
+            Error::<Test>::DestructionNotStarted
+        );
+    });
+}
+
+#[test]
+fn destroy_accumulated_batches_across_calls_and_clears_the_cursor() {
+    new_test_ext().execute_with(|| {
+        // RemoveKeyLimit is 3, so 4 commodities require two calls to fully destroy.
+        for i in 0..4u8 {
+            assert_ok!(Commodity::mint(Origin::root(), 1, info(i)));
+        }
+        assert_eq!(Commodity::total_for_account(&1), 4);
+
+        assert_ok!(Commodity::start_destroy(Origin::root(), 1));
+
+        assert_ok!(Commodity::destroy_accumulated(Origin::root(), 1));
+        assert_eq!(Commodity::total_for_account(&1), 1);
+        assert!(Commodity::destroy_cursor(&1).is_some());
+
+        assert_ok!(Commodity::destroy_accumulated(Origin::root(), 1));
+        assert_eq!(Commodity::total_for_account(&1), 0);
+        assert!(Commodity::destroy_cursor(&1).is_none());
+        assert_eq!(Commodity::assets_for_account(&1).len(), 0);
+    });
+}
+
+#[test]
+fn assets_for_account_returns_every_owned_commodity_regardless_of_paging_order() {
+    new_test_ext().execute_with(|| {
+        let expected: BTreeSet<_> = (0..4u8)
+            .map(|i| {
+                assert_ok!(Commodity::mint(Origin::root(), 1, info(i)));
+                <Test as frame_system::Trait>::Hashing::hash_of(&info(i))
+            })
+            .collect();
+
+        let owned: BTreeSet<_> = Commodity::assets_for_account(&1)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(owned, expected);
+        assert_eq!(owned.len(), 4);
+    });
+}
+
+#[test]
+fn frozen_commodity_cannot_be_transferred_or_burned() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Commodity::mint(Origin::root(), 1, info(0)));
+        let commodity_id = Commodity::assets_for_account(&1)[0].0;
+
+        assert_ok!(Commodity::freeze(Origin::root(), commodity_id));
+        assert_noop!(
+            Commodity::transfer(Origin::signed(1), 2, commodity_id),
+            Error::<Test>::Frozen
+        );
+        assert_noop!(
+            Commodity::burn(Origin::signed(1), commodity_id),
+            Error::<Test>::Frozen
+        );
+
+        assert_ok!(Commodity::thaw(Origin::root(), commodity_id));
+        assert_ok!(Commodity::transfer(Origin::signed(1), 2, commodity_id));
+    });
+}
+
+#[test]
+fn destroy_accumulated_refuses_to_remove_a_frozen_commodity() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Commodity::mint(Origin::root(), 1, info(0)));
+        let commodity_id = Commodity::assets_for_account(&1)[0].0;
+        assert_ok!(Commodity::freeze(Origin::root(), commodity_id));
+
+        assert_ok!(Commodity::start_destroy(Origin::root(), 1));
+        assert_noop!(
+            Commodity::destroy_accumulated(Origin::root(), 1),
+            Error::<Test>::Frozen
+        );
+        // the frozen commodity must survive the failed attempt untouched.
+        assert_eq!(Commodity::total_for_account(&1), 1);
+        assert!(Commodity::is_frozen(&commodity_id));
+
+        assert_ok!(Commodity::thaw(Origin::root(), commodity_id));
+        assert_ok!(Commodity::destroy_accumulated(Origin::root(), 1));
+        assert_eq!(Commodity::total_for_account(&1), 0);
+    });
+}
@@ -0,0 +1,73 @@
+//! Weights for the commodity pallet.
+//!
+//! `mint`, `burn`, and `transfer` are O(1) storage operations against `OwnedCommodities` (a
+//! `double_map`), so their cost does not depend on how many commodities the account already
+//! holds. `destroy_accumulated` removes up to `n` commodities in one call, so its cost scales
+//! with `n`.
+//!
+//! The values below are hand-written estimates, not the output of a run of the benchmarks in
+//! `benchmarking.rs`.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::weights::{constants::RocksDbWeight, Weight};
+
+/// Weight functions needed for the commodity pallet.
+pub trait WeightInfo {
+    fn mint() -> Weight;
+    fn burn() -> Weight;
+    fn transfer() -> Weight;
+    fn destroy_accumulated(n: u32) -> Weight;
+}
+
+/// Weights for the commodity pallet using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(sp_std::marker::PhantomData<T>);
+impl<T: frame_system::Trait> WeightInfo for SubstrateWeight<T> {
+    fn mint() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    fn burn() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+    }
+    fn transfer() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+    }
+    fn destroy_accumulated(n: u32) -> Weight {
+        (15_000_000 as Weight)
+            .saturating_add((750_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add(RocksDbWeight::get().reads((1 + 2 * n) as Weight))
+            .saturating_add(RocksDbWeight::get().writes((2 + 6 * n) as Weight))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    fn mint() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    fn burn() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+    }
+    fn transfer() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+    }
+    fn destroy_accumulated(n: u32) -> Weight {
+        (15_000_000 as Weight)
+            .saturating_add((750_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add(RocksDbWeight::get().reads((1 + 2 * n) as Weight))
+            .saturating_add(RocksDbWeight::get().writes((2 + 6 * n) as Weight))
+    }
+}